@@ -0,0 +1,45 @@
+use smart_leds::RGB8;
+
+// FastLED-style fixed-point helpers shared by the animations, so brightness
+// and color-ramp math stays integer-only and never underflows/overflows.
+
+// scale an 8-bit value by another 8-bit value, treating `scale` as a
+// fraction out of 255 (scale8(x, 255) == x, scale8(x, 0) == 0)
+pub fn scale8(x: u8, scale: u8) -> u8 {
+    ((x as u16 * scale as u16) / 255) as u8
+}
+
+// dim an RGB8 color in place by `scale` (out of 255) on every channel
+pub fn nscale8(color: &mut RGB8, scale: u8) {
+    color.r = scale8(color.r, scale);
+    color.g = scale8(color.g, scale);
+    color.b = scale8(color.b, scale);
+}
+
+// full-cycle 256-entry sine lookup table: sin8(theta) maps theta in 0..=255
+// (one full turn) to 128 + 127*sin(theta), centered on 128, avoiding any
+// floating point at runtime
+const SIN8_TABLE: [u8; 256] = [
+    128, 131, 134, 137, 140, 144, 147, 150, 153, 156, 159, 162, 165, 168, 171, 174,
+    177, 179, 182, 185, 188, 191, 193, 196, 199, 201, 204, 206, 209, 211, 213, 216,
+    218, 220, 222, 224, 226, 228, 230, 232, 234, 235, 237, 239, 240, 241, 243, 244,
+    245, 246, 248, 249, 250, 250, 251, 252, 253, 253, 254, 254, 254, 255, 255, 255,
+    255, 255, 255, 255, 254, 254, 254, 253, 253, 252, 251, 250, 250, 249, 248, 246,
+    245, 244, 243, 241, 240, 239, 237, 235, 234, 232, 230, 228, 226, 224, 222, 220,
+    218, 216, 213, 211, 209, 206, 204, 201, 199, 196, 193, 191, 188, 185, 182, 179,
+    177, 174, 171, 168, 165, 162, 159, 156, 153, 150, 147, 144, 140, 137, 134, 131,
+    128, 125, 122, 119, 116, 112, 109, 106, 103, 100, 97, 94, 91, 88, 85, 82,
+    79, 77, 74, 71, 68, 65, 63, 60, 57, 55, 52, 50, 47, 45, 43, 40,
+    38, 36, 34, 32, 30, 28, 26, 24, 22, 21, 19, 17, 16, 15, 13, 12,
+    11, 10, 8, 7, 6, 6, 5, 4, 3, 3, 2, 2, 2, 1, 1, 1,
+    1, 1, 1, 1, 2, 2, 2, 3, 3, 4, 5, 6, 6, 7, 8, 10,
+    11, 12, 13, 15, 16, 17, 19, 21, 22, 24, 26, 28, 30, 32, 34, 36,
+    38, 40, 43, 45, 47, 50, 52, 55, 57, 60, 63, 65, 68, 71, 74, 77,
+    79, 82, 85, 88, 91, 94, 97, 100, 103, 106, 109, 112, 116, 119, 122, 125,
+];
+
+// sin8(theta): one full sine cycle over theta in 0..=255, output 1..=255
+// centered on 128 (i.e. sin8(0) == 128, sin8(64) == 255, sin8(192) == 1)
+pub fn sin8(theta: u8) -> u8 {
+    SIN8_TABLE[theta as usize]
+}
@@ -0,0 +1,689 @@
+use core::f32::consts::PI;
+use micromath::F32Ext;
+
+use accelerometer::vector::F32x3;
+use smart_leds::RGB8;
+
+mod math;
+use math::{nscale8, scale8, sin8};
+
+pub const WIDTH : usize = 8;
+pub const HEIGHT : usize = 8;
+pub const NUM_PX : usize = WIDTH*HEIGHT;
+
+// common interface implemented by every animation so `main` can drive
+// whichever one is active without knowing its concrete type
+pub trait Animation {
+    fn next(&mut self);
+    fn to_list(&self) -> [RGB8; NUM_PX];
+
+    // animations that react to the live accelerometer vector (e.g. nmGravity)
+    // override this; everything else just falls through to the plain tick
+    fn next_with_accel(&mut self, _accel: F32x3) {
+        self.next()
+    }
+}
+
+// pulse implementation
+pub struct nmPulse {
+    strip: [RGB8; NUM_PX],
+    color: RGB8,
+    px_counter: u8,
+    descending: bool,
+    step_size: u8,
+    brightness: u8,
+}
+
+impl nmPulse {
+    // constructor fn
+    pub fn new(brightness: u8, step_size: u8) -> nmPulse {
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color: RGB8::new(brightness,brightness,brightness),
+            px_counter: 0,
+            descending: false,
+            step_size: step_size,
+            brightness: brightness,
+        }
+    }
+
+    // set an LED color
+    pub fn set(&mut self, color: RGB8) {
+        for px in self.strip.iter_mut() {
+            *px = color;
+        }
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        if self.px_counter <= 1 {
+            self.descending = false;
+        } else if self.px_counter >= self.brightness {
+            self.descending = true;
+        }
+        if self.descending == true {
+            self.px_counter -= self.step_size;
+        } else {
+            self.px_counter += self.step_size;
+        }
+
+        self.set(RGB8::new(self.px_counter, self.px_counter, self.px_counter));
+    }
+}
+
+impl Animation for nmPulse {
+    fn next(&mut self) { nmPulse::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmPulse::to_list(self) }
+}
+
+
+// sprial implementation
+pub struct nmSnake
+{
+    strip: [RGB8; NUM_PX],
+    color: RGB8,
+    delta: bool,
+    row: usize,
+    col: usize,
+}
+
+impl nmSnake {
+    // constructor
+    pub fn new(color: RGB8) -> nmSnake {
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color: color,
+            delta: true,
+            row: 0,
+            col: 0,
+        }
+    }
+
+    // set pixels at (row,col)
+    pub fn set(&mut self){
+        for (idx, px) in self.strip.iter_mut().enumerate() {
+            if idx == self.col*WIDTH + self.row {
+                *px = self.color;
+            } else {
+                *px = RGB8::new(0,0,0);
+            }
+        }
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        // bounce the row value
+        if self.row == WIDTH-1 {
+            self.delta = false;
+            self.col = (self.col + 1) % 8;
+        } else if self.row == 0 {
+            self.delta = true;
+            self.col = (self.col + 1) % 8;
+        }
+        if self.delta { self.row += 1 } else { self.row -= 1 };
+        // update
+        self.set();
+    }
+
+}
+
+impl Animation for nmSnake {
+    fn next(&mut self) { nmSnake::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmSnake::to_list(self) }
+}
+
+
+// wave implementation
+const NUM_SHADOWS: usize = 7;
+pub struct nmWave
+{
+    strip: [RGB8; NUM_PX],
+    color: RGB8,
+    row: usize,
+    shadows: [usize; NUM_SHADOWS],
+}
+
+impl nmWave {
+    // constructor
+    pub fn new(color: RGB8) -> nmWave {
+        let mut shadows: [usize; NUM_SHADOWS] = [0; NUM_SHADOWS];
+        for i in 0..NUM_SHADOWS {
+            shadows[i] = NUM_SHADOWS - 1 - i;
+        }
+
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color: color,
+            row: NUM_SHADOWS,
+            shadows: shadows,
+        }
+    }
+
+    // set row of pixels
+    pub fn set(&mut self, row: usize, color: RGB8) {
+        let mut col: usize = 0;
+        for (idx, px) in self.strip.iter_mut().enumerate() {
+            if idx == col*WIDTH + row {
+                *px = color;
+                col += 1;
+            } 
+        }
+    }
+
+    // clear all pixels
+    pub fn clear(&mut self) {
+        for px in self.strip.iter_mut() {
+            *px = RGB8::new(0,0,0);
+        }
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        // update row value
+        self.row = (self.row +1) % WIDTH;
+
+        let intensity_step: u8 = NUM_SHADOWS as u8;
+
+        // clear rows
+        self.clear();
+
+        // draw original row
+        self.set(self.row, self.color);
+
+        // draw shadow rows, each dimmer than the last
+        for i in 0..=(self.shadows.len()-1) {
+            // update shadow row value
+            self.shadows[i] = (self.shadows[i] + 1) % WIDTH;
+
+            // nscale8 dims without the old r - r/step*(i+1) underflowing;
+            // widen to u16 before multiplying so this doesn't just trade that
+            // underflow for a 255*remaining overflow
+            let remaining = intensity_step - (i+1) as u8;
+            let scale = (255u16 * remaining as u16 / intensity_step as u16) as u8;
+            let mut dimmed_color = self.color;
+            nscale8(&mut dimmed_color, scale);
+
+            self.set(self.shadows[i], dimmed_color);
+        }
+    }
+
+}
+
+impl Animation for nmWave {
+    fn next(&mut self) { nmWave::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmWave::to_list(self) }
+}
+
+
+// sin implementation
+const SIN_SIZE: usize = 14*WIDTH/8;
+pub struct nmSin
+{
+    strip: [RGB8; NUM_PX],
+    color: RGB8,
+    window: [usize; WIDTH],
+    sin: [usize; SIN_SIZE],
+}
+
+impl nmSin {
+    // constructor
+    pub fn new(color: RGB8) -> nmSin {
+        // init sin pattern
+        let amplitude: f32 = (HEIGHT) as f32/2.0;
+        let offset: f32 = (HEIGHT) as f32/2.0;
+
+        let mut sin: [usize; SIN_SIZE]  = [0; SIN_SIZE];
+        for i in 0..SIN_SIZE {
+            let value: usize = (amplitude * (f32::sin(i as f32 * (2.0 * PI / (SIN_SIZE) as f32))) + offset).round() as usize;
+            sin[i] = value;
+        }
+
+        // start window as front of sin wave
+        let mut window: [usize; WIDTH]  = [0; WIDTH];
+        for i in 0..WIDTH {
+            window[i] = sin[i];
+        }
+
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color: color,
+            window: window,
+            sin: sin,
+        }
+    }
+
+    // set row of pixels up to height
+    pub fn set_row_height(&mut self, row: usize, height: usize) {
+        let mut col: usize = 0;
+        for (idx, px) in self.strip.iter_mut().enumerate() {
+            if idx == col*WIDTH + row {
+                *px = self.color;
+                if col < height {
+                    col += 1;
+                }
+            } 
+        }
+    }
+
+    // clear all pixels
+    pub fn clear(&mut self) {
+        for px in self.strip.iter_mut() {
+            *px = RGB8::new(0,0,0);
+        }
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        // draw frame/row
+        self.clear();
+        for i in 0..WIDTH {
+            // ignore 0
+            if self.window[i] != 0 {
+                self.set_row_height(i, self.window[i]-1);
+            }
+        }
+
+        // update sin
+        for i in 0..SIN_SIZE {
+            self.sin[i] = self.sin[(i+1) % (SIN_SIZE)];
+        }
+
+        // update window
+        for i in 0..WIDTH {
+            self.window[i] = self.sin[i];
+        }
+    }
+
+}
+
+impl Animation for nmSin {
+    fn next(&mut self) { nmSin::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmSin::to_list(self) }
+}
+
+
+// tiny xorshift PRNG so the fire animation doesn't need an external rand crate
+struct Xorshift32 {
+    state: u32,
+}
+
+impl Xorshift32 {
+    fn new(seed: u32) -> Xorshift32 {
+        Self { state: if seed == 0 { 0xACE1_u32 } else { seed } }
+    }
+
+    fn next_u8(&mut self) -> u8 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        (x & 0xFF) as u8
+    }
+}
+
+
+// fire implementation (Fire2012-style heat simulation, one flame per column)
+const COOLING: u8 = 55;
+const SPARKING: u8 = 120;
+
+pub struct nmFire
+{
+    strip: [RGB8; NUM_PX],
+    heat: [u8; NUM_PX],
+    rng: Xorshift32,
+}
+
+impl nmFire {
+    // constructor; seed the PRNG once (e.g. from LIS3DH noise)
+    pub fn new(seed: u32) -> nmFire {
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            heat: [0; NUM_PX],
+            rng: Xorshift32::new(seed),
+        }
+    }
+
+    // heat[x][y] lives at row HEIGHT-1-y so y=0 is the bottom (spark) row
+    fn index(x: usize, y: usize) -> usize {
+        (HEIGHT - 1 - y) * WIDTH + x
+    }
+
+    // map a 0..255 heat value to an ember color: black -> red -> yellow -> white
+    fn heat_color(heat: u8) -> RGB8 {
+        let t192: u8 = ((heat as u16 * 191) / 255) as u8;
+        let heatramp: u8 = (t192 & 0x3F) << 2;
+
+        if t192 >= 0x80 {
+            RGB8::new(255, 255, heatramp)
+        } else if t192 >= 0x40 {
+            RGB8::new(255, heatramp, 0)
+        } else {
+            RGB8::new(heatramp, 0, 0)
+        }
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        for x in 0..WIDTH {
+            // 1. cool each cell down a little
+            for y in 0..HEIGHT {
+                let idx = Self::index(x, y);
+                let cooldown = self.rng.next_u8() % (((COOLING as u16 * 10 / HEIGHT as u16) + 2) as u8);
+                self.heat[idx] = self.heat[idx].saturating_sub(cooldown);
+            }
+
+            // 2. heat drifts up and diffuses a little
+            for y in (2..HEIGHT).rev() {
+                let below1 = self.heat[Self::index(x, y-1)] as u16;
+                let below2 = self.heat[Self::index(x, y-2)] as u16;
+                self.heat[Self::index(x, y)] = ((below1 + below2 + below2) / 3) as u8;
+            }
+
+            // 3. randomly ignite a new spark near the bottom
+            if self.rng.next_u8() < SPARKING {
+                let idx = Self::index(x, 0);
+                let spark = 160_u8.saturating_add(self.rng.next_u8() % 96);
+                self.heat[idx] = self.heat[idx].saturating_add(spark);
+            }
+
+            // 4. map heat to color
+            for y in 0..HEIGHT {
+                let idx = Self::index(x, y);
+                self.strip[idx] = Self::heat_color(self.heat[idx]);
+            }
+        }
+    }
+}
+
+impl Animation for nmFire {
+    fn next(&mut self) { nmFire::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmFire::to_list(self) }
+}
+
+
+// noise implementation: scrolling 2D value-noise plasma over a 2-color gradient
+pub struct nmNoise
+{
+    strip: [RGB8; NUM_PX],
+    color_lo: RGB8,
+    color_hi: RGB8,
+    scale: u8,
+    time: u16, // Q8.8 fixed-point time coordinate
+}
+
+// how far the Q8.8 time coordinate advances per next(); smaller is slower flow
+const NOISE_TIME_STEP: u16 = 24;
+
+impl nmNoise {
+    // constructor; scale controls spatial frequency (higher = busier plasma)
+    pub fn new(color_lo: RGB8, color_hi: RGB8, scale: u8) -> nmNoise {
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color_lo: color_lo,
+            color_hi: color_hi,
+            scale: scale,
+            time: 0,
+        }
+    }
+
+    // cheap integer hash of a lattice corner, used as that corner's noise value
+    fn hash(x: u32, y: u32, t: u32) -> u8 {
+        let mut h = x.wrapping_mul(374_761_393)
+            .wrapping_add(y.wrapping_mul(668_265_263))
+            .wrapping_add(t.wrapping_mul(2_147_483_647));
+        h ^= h >> 13;
+        h = h.wrapping_mul(1_274_126_177);
+        h ^= h >> 16;
+        (h & 0xFF) as u8
+    }
+
+    // linearly blend a -> b by frac/255, in 8-bit fixed point
+    fn lerp8(a: u8, b: u8, frac: u8) -> u8 {
+        scale8(a, 255 - frac) + scale8(b, frac)
+    }
+
+    // the noise value at one integer time tick, bilinearly interpolated
+    // across the Q8.8 fixed-point (nx, ny) lattice
+    fn sample_at_tick(x0: u32, y0: u32, fx: u8, fy: u8, t: u32) -> u8 {
+        let v00 = Self::hash(x0, y0, t);
+        let v10 = Self::hash(x0 + 1, y0, t);
+        let v01 = Self::hash(x0, y0 + 1, t);
+        let v11 = Self::hash(x0 + 1, y0 + 1, t);
+
+        let top = Self::lerp8(v00, v10, fx);
+        let bottom = Self::lerp8(v01, v11, fx);
+        Self::lerp8(top, bottom, fy)
+    }
+
+    // bilinearly interpolated value-noise sample at Q8.8 fixed-point (nx, ny),
+    // additionally interpolated across the time axis (between the two
+    // neighbouring integer ticks) so the field flows smoothly instead of
+    // flickering to an uncorrelated value every tick
+    fn sample(&self, nx: u16, ny: u16) -> u8 {
+        let t0 = (self.time >> 8) as u32;
+        let flow = sin8(t0 as u8) as u16;
+        let ny = ny.wrapping_add(flow);
+
+        let x0 = (nx >> 8) as u32;
+        let y0 = (ny >> 8) as u32;
+        let fx = (nx & 0xFF) as u8;
+        let fy = (ny & 0xFF) as u8;
+        let ft = (self.time & 0xFF) as u8;
+
+        let lo = Self::sample_at_tick(x0, y0, fx, fy, t0);
+        let hi = Self::sample_at_tick(x0, y0, fx, fy, t0 + 1);
+        Self::lerp8(lo, hi, ft)
+    }
+
+    // blend the two configured colors by the noise value (0 -> color_lo, 255 -> color_hi)
+    fn palette_lookup(&self, value: u8) -> RGB8 {
+        let mut lo = self.color_lo;
+        let mut hi = self.color_hi;
+        nscale8(&mut lo, 255 - value);
+        nscale8(&mut hi, value);
+        RGB8::new(
+            lo.r.saturating_add(hi.r),
+            lo.g.saturating_add(hi.g),
+            lo.b.saturating_add(hi.b),
+        )
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let nx = (x as u16) * self.scale as u16;
+                let ny = (y as u16) * self.scale as u16;
+                let value = self.sample(nx, ny);
+                self.strip[y*WIDTH + x] = self.palette_lookup(value);
+            }
+        }
+        self.time = self.time.wrapping_add(NOISE_TIME_STEP);
+    }
+}
+
+impl Animation for nmNoise {
+    fn next(&mut self) { nmNoise::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmNoise::to_list(self) }
+}
+
+
+// gravity implementation: a single grain tilts around the matrix, driven by
+// the live LIS3DH acceleration vector
+const GRAVITY_DAMPING: f32 = 0.92;
+const GRAVITY_DT: f32 = 0.15;
+
+pub struct nmGravity
+{
+    strip: [RGB8; NUM_PX],
+    color: RGB8,
+    x: f32,
+    y: f32,
+    vx: f32,
+    vy: f32,
+}
+
+impl nmGravity {
+    // constructor; the grain starts at rest in the middle of the matrix
+    pub fn new(color: RGB8) -> nmGravity {
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color: color,
+            x: (WIDTH - 1) as f32 / 2.0,
+            y: (HEIGHT - 1) as f32 / 2.0,
+            vx: 0.0,
+            vy: 0.0,
+        }
+    }
+
+    // clear all pixels
+    fn clear(&mut self) {
+        for px in self.strip.iter_mut() {
+            *px = RGB8::new(0,0,0);
+        }
+    }
+
+    // splat the grain onto its four nearest pixels, weighted by distance
+    fn render(&mut self) {
+        self.clear();
+
+        let x0 = self.x.floor();
+        let y0 = self.y.floor();
+        let fx = self.x - x0;
+        let fy = self.y - y0;
+
+        let corners = [
+            (x0 as i32,     y0 as i32,     (1.0 - fx) * (1.0 - fy)),
+            (x0 as i32 + 1, y0 as i32,     fx * (1.0 - fy)),
+            (x0 as i32,     y0 as i32 + 1, (1.0 - fx) * fy),
+            (x0 as i32 + 1, y0 as i32 + 1, fx * fy),
+        ];
+
+        for (cx, cy, weight) in corners {
+            if cx < 0 || cy < 0 || cx as usize >= WIDTH || cy as usize >= HEIGHT {
+                continue;
+            }
+            let idx = (cy as usize) * WIDTH + cx as usize;
+            let mut px = self.color;
+            nscale8(&mut px, (weight * 255.0) as u8);
+            self.strip[idx] = px;
+        }
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    // plain tick with no tilt input: just hold the grain where it is
+    pub fn next(&mut self) {
+        self.render();
+    }
+
+    // integrate the grain's motion against the live accelerometer vector
+    pub fn next_with_accel(&mut self, accel: F32x3) {
+        self.vx = (self.vx + accel.x * GRAVITY_DT) * GRAVITY_DAMPING;
+        self.vy = (self.vy + accel.y * GRAVITY_DT) * GRAVITY_DAMPING;
+
+        self.x += self.vx;
+        self.y += self.vy;
+
+        // bounce off the matrix edges
+        let max_x = (WIDTH - 1) as f32;
+        let max_y = (HEIGHT - 1) as f32;
+        if self.x < 0.0 { self.x = 0.0; self.vx = -self.vx; }
+        if self.x > max_x { self.x = max_x; self.vx = -self.vx; }
+        if self.y < 0.0 { self.y = 0.0; self.vy = -self.vy; }
+        if self.y > max_y { self.y = max_y; self.vy = -self.vy; }
+
+        self.render();
+    }
+}
+
+impl Animation for nmGravity {
+    fn next(&mut self) { nmGravity::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmGravity::to_list(self) }
+    fn next_with_accel(&mut self, accel: F32x3) { nmGravity::next_with_accel(self, accel) }
+}
+
+
+// comet implementation: a bright head bounces along the strip, fading from
+// color1 at the head to color2 over tail_len pixels of distance
+pub struct nmComet
+{
+    strip: [RGB8; NUM_PX],
+    color1: RGB8,
+    color2: RGB8,
+    tail_len: usize,
+    head: usize,
+    delta: bool,
+}
+
+impl nmComet {
+    // constructor
+    pub fn new(color1: RGB8, color2: RGB8, tail_len: usize) -> nmComet {
+        Self {
+            strip: [RGB8::new(0,0,0); NUM_PX],
+            color1: color1,
+            color2: color2,
+            tail_len: tail_len,
+            head: 0,
+            delta: true,
+        }
+    }
+
+    // blend color1 (at the head) into color2 as distance grows, reaching pure
+    // color2 at tail_len and staying there beyond it
+    fn blend(&self, distance: usize) -> RGB8 {
+        let max = self.tail_len.max(1) as u16;
+        let distance = (distance as u16).min(max);
+
+        RGB8::new(
+            ((self.color1.r as u16 * (max - distance) + self.color2.r as u16 * distance) / max) as u8,
+            ((self.color1.g as u16 * (max - distance) + self.color2.g as u16 * distance) / max) as u8,
+            ((self.color1.b as u16 * (max - distance) + self.color2.b as u16 * distance) / max) as u8,
+        )
+    }
+
+    pub fn to_list(&self) -> [RGB8; NUM_PX] {
+        self.strip
+    }
+
+    pub fn next(&mut self) {
+        // bounce the head back and forth (same edge logic as nmSnake's row)
+        if self.head == NUM_PX-1 {
+            self.delta = false;
+        } else if self.head == 0 {
+            self.delta = true;
+        }
+        if self.delta { self.head += 1 } else { self.head -= 1 };
+
+        for (i, px) in self.strip.iter_mut().enumerate() {
+            let distance = if i > self.head { i - self.head } else { self.head - i };
+            *px = self.blend(distance);
+        }
+    }
+}
+
+impl Animation for nmComet {
+    fn next(&mut self) { nmComet::next(self) }
+    fn to_list(&self) -> [RGB8; NUM_PX] { nmComet::to_list(self) }
+}
\ No newline at end of file
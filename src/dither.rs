@@ -0,0 +1,73 @@
+use smart_leds::RGB8;
+
+use crate::animations::NUM_PX;
+
+// Temporal dithering / bit-angle-modulation layer sitting between an
+// animation's to_list() and neopixels.write(). It holds each channel at
+// `frame_depth` extra bits of resolution and, frame by frame, rounds up or
+// down so the time-averaged output hits that higher-resolution target --
+// software PWM applied across frames instead of across LED rows.
+// set_target is the only way to load a target and it takes 8-bit frames, so
+// the extra low bits are always zero and next_frame returns each loaded
+// frame unchanged.
+pub struct Dither {
+    frame_depth: u8,
+    target: [(u16, u16, u16); NUM_PX],
+    error: [(u16, u16, u16); NUM_PX],
+}
+
+impl Dither {
+    // constructor; frame_depth extra bits of target resolution (e.g. 4
+    // holds each 8-bit channel as a 12-bit target)
+    pub fn new(frame_depth: u8) -> Dither {
+        Self {
+            frame_depth: frame_depth,
+            target: [(0, 0, 0); NUM_PX],
+            error: [(0, 0, 0); NUM_PX],
+        }
+    }
+
+    // load a fresh 8-bit animation frame as the new target, shifted up by
+    // frame_depth bits (its extra low bits are zero)
+    pub fn set_target(&mut self, frame: &[RGB8; NUM_PX]) {
+        for (i, px) in frame.iter().enumerate() {
+            self.target[i] = (
+                (px.r as u16) << self.frame_depth,
+                (px.g as u16) << self.frame_depth,
+                (px.b as u16) << self.frame_depth,
+            );
+        }
+    }
+
+    // produce this frame's 8-bit output, carrying the rounding error forward
+    // so the average over 2^frame_depth frames matches the target exactly
+    pub fn next_frame(&mut self) -> [RGB8; NUM_PX] {
+        let mask: u16 = (1 << self.frame_depth) - 1;
+        let mut out = [RGB8::new(0, 0, 0); NUM_PX];
+
+        for i in 0..NUM_PX {
+            let (tr, tg, tb) = self.target[i];
+            let (er, eg, eb) = self.error[i];
+
+            let (vr, nr) = self.dither_channel(tr, er, mask);
+            let (vg, ng) = self.dither_channel(tg, eg, mask);
+            let (vb, nb) = self.dither_channel(tb, eb, mask);
+
+            out[i] = RGB8::new(vr, vg, vb);
+            self.error[i] = (nr, ng, nb);
+        }
+
+        out
+    }
+
+    // one channel's step: fold this frame's fraction of the sub-8-bit
+    // remainder into the running error, emitting a carry bit whenever it
+    // overflows back past the 8-bit boundary
+    fn dither_channel(&self, target: u16, error: u16, mask: u16) -> (u8, u16) {
+        let sum = error + (target & mask);
+        let carry = sum >> self.frame_depth;
+        let remainder = sum & mask;
+        let value = (target >> self.frame_depth) + carry;
+        (value.min(255) as u8, remainder)
+    }
+}
@@ -25,10 +25,15 @@ use adafruit_feather_rp2040::{
 use lis3dh::{Lis3dh, SlaveAddr};
 use accelerometer::{Accelerometer, vector::F32x3};
 use ws2812_pio::Ws2812;
+use embedded_hal::serial::Read as _;
 
 /***** custom module imports *****/
 mod animations;
-use animations::{nmPulse, nmSnake, nmWave, nmSin};
+use animations::{Animation, nmPulse, nmSnake, nmWave, nmSin, nmFire, nmNoise, nmGravity, nmComet};
+mod dither;
+use dither::Dither;
+mod realtime;
+use realtime::Realtime;
 use smart_leds::{RGB8, SmartLedsWrite};
 
 
@@ -81,6 +86,18 @@ fn main() -> ! {
     lis3dh.set_range(lis3dh::Range::G2).unwrap(); // Set the accelerometer range
     lis3dh.set_datarate(lis3dh::DataRate::Hz_100).unwrap(); // Set the data rate
 
+    // Initialize UART0 for realtime pixel streaming (WLED-style DRGB/DNRGB)
+    let uart_pins = (
+        pins.tx.into_function::<hal::gpio::FunctionUart>(),
+        pins.rx.into_function::<hal::gpio::FunctionUart>(),
+    );
+    let mut uart = hal::uart::UartPeripheral::new(pac.UART0, uart_pins, &mut pac.RESETS)
+        .enable(
+            hal::uart::UartConfig::new(115_200.Hz(), hal::uart::DataBits::Eight, None, hal::uart::StopBits::One),
+            clocks.peripheral_clock.freq(),
+        )
+        .unwrap();
+
     // instantiate ws2812
     let (mut pio0, sm0, _, _, _) = pac.PIO0.split(&mut pac.RESETS);
     let mut neopixels = Ws2812::new(
@@ -101,10 +118,45 @@ fn main() -> ! {
     let mut nm_snake = nmSnake::new(RGB8::new(255, 0 ,0));
     let mut nm_wave = nmWave::new(RGB8::new(0, 0, 50));
     let mut nm_sin = nmSin::new(RGB8::new(0, 30, 0));
-    
+    let mut nm_fire = nmFire::new(0xA5A5_1234);
+    let mut nm_noise = nmNoise::new(RGB8::new(0, 0, 40), RGB8::new(255, 0, 120), 48);
+    let mut nm_gravity = nmGravity::new(RGB8::new(0, 80, 255));
+    let mut nm_comet = nmComet::new(RGB8::new(255, 255, 255), RGB8::new(0, 0, 60), 12);
+
+    // animations indexed by mode; only the active one is advanced each tick
+    let mut animations: [&mut dyn Animation; 8] = [
+        &mut nm_wave,
+        &mut nm_pulse,
+        &mut nm_sin,
+        &mut nm_snake,
+        &mut nm_fire,
+        &mut nm_noise,
+        &mut nm_gravity,
+        &mut nm_comet,
+    ];
+
+    // temporal dithering layer with 4 extra bits per channel; the animations
+    // hand it 8-bit frames, so each sub-frame it writes equals the latest
+    // animation frame
+    let mut dither = Dither::new(4);
+
+    // realtime mode: pixels are streamed in over UART0 (WLED-style DRGB);
+    // completed frames go straight to the matrix, bypassing dithering/local
+    // animations, and it falls back to mode 0 once the host goes quiet
+    let mut realtime = Realtime::new(500);
+    const MODE_REALTIME : u8 = 8;
+
+    // modes 0-3 are orientation-selected (see below); modes 4..animations.len()
+    // are the bonus animations with no orientation of their own, so while the
+    // board is resting flat we slowly cycle through them instead of freezing
+    const BASE_MODES : u8 = 4;
+    let mut idle_cycle : u8 = 0;
+    let mut idle_cycle_ticks : u32 = 0;
+    const IDLE_CYCLE_TICKS : u32 = 400; // ~2s at the 5ms loop delay
+
     // loop vals
     let mut nticks : u8 = 9;
-    let mut mode : u8 = 5;
+    let mut mode : u8 = BASE_MODES;
 
     // constants
     const THRESH : f32 = 0.8;
@@ -113,40 +165,70 @@ fn main() -> ! {
         // Read X, Y, Z values
         let accel_data: F32x3 = lis3dh.accel_norm().unwrap();
 
-        // Choose animation based on oritentation
-        if accel_data.x > THRESH {
-            mode = 0;
-        }
-        else if accel_data.x < -THRESH {
-            mode = 1;
+        // drain any bytes the host has streamed over UART; receiving data
+        // switches us into realtime mode and completed frames are written
+        // straight to the matrix
+        let mut received_byte = false;
+        while let Ok(byte) = uart.read() {
+            received_byte = true;
+            mode = MODE_REALTIME;
+            if let Some(frame) = realtime.feed(byte) {
+                neopixels.write(frame.iter().cloned()).unwrap();
+            }
         }
-        else if accel_data.y > THRESH {
-            mode = 2;
-        }
-        else if accel_data.y < -THRESH {
-            mode = 3;
+
+        if mode == MODE_REALTIME {
+            // fall back to local animations once the host goes quiet
+            if !received_byte && realtime.tick_timeout(5) {
+                mode = 0;
+            }
+        } else {
+            // Choose animation based on oritentation
+            if accel_data.x > THRESH {
+                mode = 0;
+            }
+            else if accel_data.x < -THRESH {
+                mode = 1;
+            }
+            else if accel_data.y > THRESH {
+                mode = 2;
+            }
+            else if accel_data.y < -THRESH {
+                mode = 3;
+            }
+            else {
+                // resting flat: slowly cycle through the bonus animations
+                // instead of freezing on whichever mode was last active
+                idle_cycle_ticks += 1;
+                if idle_cycle_ticks >= IDLE_CYCLE_TICKS {
+                    idle_cycle_ticks = 0;
+                    idle_cycle = (idle_cycle + 1) % (animations.len() as u8 - BASE_MODES);
+                }
+                mode = BASE_MODES + idle_cycle;
+            }
         }
 
-        // write frame to neopixel every nticks
-        if nticks > 8 {
+        // advance the animation every nticks; it gets the live accelerometer
+        // vector in case it wants to react to tilt
+        if nticks > 8 && mode != MODE_REALTIME {
             nticks = 0;
-            // itr thru the applicable nodes
-            nm_pulse.next();
-            nm_snake.next();
-            nm_wave.next();
-            nm_sin.next();
-
-            // select list based off current node
-            let ds: [RGB8; animations::NUM_PX] = match mode {
-                0 => nm_wave.to_list(),
-                1 => nm_pulse.to_list(),
-                2 => nm_sin.to_list(),
-                3 => nm_snake.to_list(),
-                _ => [RGB8::new(0,0,0); animations::NUM_PX],
+
+            let ds: [RGB8; animations::NUM_PX] = match animations.get_mut(mode as usize) {
+                Some(anim) => {
+                    anim.next_with_accel(accel_data);
+                    anim.to_list()
+                }
+                None => [RGB8::new(0,0,0); animations::NUM_PX],
             };
 
-            // write to neomatrix
-            neopixels.write(ds.iter().cloned()).unwrap();
+            dither.set_target(&ds);
+        }
+
+        // write a sub-frame from the dither layer every loop iteration (for
+        // 8-bit animation frames this is the latest frame unchanged); realtime
+        // frames were already written straight through above
+        if mode != MODE_REALTIME {
+            neopixels.write(dither.next_frame().iter().cloned()).unwrap();
         }
 
         nticks += 1;
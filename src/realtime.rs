@@ -0,0 +1,111 @@
+use smart_leds::RGB8;
+
+use crate::animations::NUM_PX;
+
+// WLED-compatible realtime frame headers: DRGB sends one R,G,B triple per
+// pixel; DNRGB additionally prefixes a 16-bit start index so only the pixels
+// from that index to the end of the matrix need to be resent. A frame is
+// complete only once the last pixel has been written.
+const HEADER_DRGB: u8 = 2;
+const HEADER_DNRGB: u8 = 4;
+
+enum State {
+    WaitHeader,
+    WaitTimeout { format: u8 },
+    WaitIndexHi,
+    WaitIndexLo { index_hi: u8 },
+    Payload { pixel: usize, channel: u8 },
+}
+
+// Streams pixel frames in from a serial/USB byte source instead of running a
+// local animation, so the matrix can be driven live by a host. Falls back to
+// local animations if the host goes quiet (see `tick_timeout`).
+pub struct Realtime {
+    state: State,
+    frame: [RGB8; NUM_PX],
+    idle_ms: u32,
+    timeout_ms: u32,
+}
+
+impl Realtime {
+    // constructor; timeout_ms is how long with no bytes before the caller
+    // should hand control back to local animations
+    pub fn new(timeout_ms: u32) -> Realtime {
+        Self {
+            state: State::WaitHeader,
+            frame: [RGB8::new(0, 0, 0); NUM_PX],
+            idle_ms: 0,
+            timeout_ms: timeout_ms,
+        }
+    }
+
+    // feed one byte from the serial/USB link; returns the completed frame
+    // once a full buffer of pixels has arrived
+    pub fn feed(&mut self, byte: u8) -> Option<[RGB8; NUM_PX]> {
+        self.idle_ms = 0;
+
+        match self.state {
+            State::WaitHeader => {
+                self.state = match byte {
+                    HEADER_DRGB | HEADER_DNRGB => State::WaitTimeout { format: byte },
+                    _ => State::WaitHeader,
+                };
+                None
+            }
+            State::WaitTimeout { format } => {
+                // WLED's real-time frames carry a timeout-in-seconds byte
+                // right after the format byte, before any pixel/index data;
+                // we track our own idle timeout in tick_timeout, so this
+                // byte just needs to be consumed and discarded
+                self.state = match format {
+                    HEADER_DRGB => State::Payload { pixel: 0, channel: 0 },
+                    _ => State::WaitIndexHi,
+                };
+                None
+            }
+            State::WaitIndexHi => {
+                self.state = State::WaitIndexLo { index_hi: byte };
+                None
+            }
+            State::WaitIndexLo { index_hi } => {
+                let start = ((index_hi as usize) << 8) | byte as usize;
+                self.state = State::Payload { pixel: start.min(NUM_PX - 1), channel: 0 };
+                None
+            }
+            State::Payload { pixel, channel } => {
+                let px = &mut self.frame[pixel];
+                match channel {
+                    0 => px.r = byte,
+                    1 => px.g = byte,
+                    _ => px.b = byte,
+                }
+
+                if channel < 2 {
+                    self.state = State::Payload { pixel: pixel, channel: channel + 1 };
+                    None
+                } else if pixel + 1 < NUM_PX {
+                    self.state = State::Payload { pixel: pixel + 1, channel: 0 };
+                    None
+                } else {
+                    // buffer is full: hand back the completed frame and wait
+                    // for the next header
+                    self.state = State::WaitHeader;
+                    Some(self.frame)
+                }
+            }
+        }
+    }
+
+    // advance the idle clock by the time since the last poll; returns true
+    // once the host has gone quiet past the timeout, so the caller should
+    // resume driving local animations
+    pub fn tick_timeout(&mut self, elapsed_ms: u32) -> bool {
+        self.idle_ms = self.idle_ms.saturating_add(elapsed_ms);
+        if self.idle_ms >= self.timeout_ms {
+            self.state = State::WaitHeader;
+            true
+        } else {
+            false
+        }
+    }
+}